@@ -1,7 +1,9 @@
 use std::process;
 use std::thread;
 use std::time;
-use sysinfo::{NetworkExt, NetworksExt, ProcessorExt, RefreshKind, System, SystemExt};
+use sysinfo::{
+    DiskExt, NetworkExt, NetworksExt, ProcessorExt, RefreshKind, System, SystemExt,
+};
 
 fn network_bytes() -> (u64, u64) {
     let refresh = RefreshKind::new().with_networks();
@@ -35,15 +37,123 @@ fn network_bandwidth(cfg: &Config) -> String {
     let seconds = cfg.interval.as_secs();
     let up_bandwidth = second_up.wrapping_sub(first_up) / seconds;
     let down_bandwidth = second_down.wrapping_sub(first_down) / seconds;
+    fmt_network(up_bandwidth, down_bandwidth, cfg)
+}
+
+fn fmt_network(up_bandwidth: u64, down_bandwidth: u64, cfg: &Config) -> String {
     let (up_name, done_name) = if cfg.with_icons {
         (" ", " ")
     } else {
         ("UP: ", "DOWN: ")
     };
     let width = 6;
-    let up_bandwidth = pretty_size(up_bandwidth, cfg.fix_length, width);
-    let down_bandwidth = pretty_size(down_bandwidth, cfg.fix_length, width);
-    format!("{up_name}{up_bandwidth:>width$}/s {done_name}{down_bandwidth:>width$}/s",)
+    let up_bandwidth = pretty_size(up_bandwidth, cfg.use_binary, cfg.fix_length, width);
+    let down_bandwidth = pretty_size(down_bandwidth, cfg.use_binary, cfg.fix_length, width);
+    let text = if cfg.basic {
+        format!("{up_bandwidth}/{down_bandwidth}")
+    } else {
+        format!("{up_name}{up_bandwidth:>width$}/s {done_name}{down_bandwidth:>width$}/s")
+    };
+    // bandwidth has no natural saturation point, so it stays the default colour
+    colorize(&text, Severity::Normal, cfg)
+}
+
+// keep whole physical disks, skip loop/ram virtual devices and partitions.
+// `sd*`/`vd*`/`hd*` partitions just append digits (`sda` -> `sda1`), but
+// `nvme*`/`mmcblk*` whole disks themselves end in a digit (`nvme0n1`,
+// `mmcblk0`) and insert a literal `p` before the partition number
+// (`nvme0n1p1`, `mmcblk0p1`), so a trailing-digit test alone is wrong.
+fn is_physical_disk(name: &str) -> bool {
+    if name.starts_with("loop") || name.starts_with("ram") {
+        return false;
+    }
+    if !name.ends_with(|c: char| c.is_ascii_digit()) {
+        return true;
+    }
+    if name.starts_with("nvme") || name.starts_with("mmcblk") {
+        // a partition has a `p` directly before the trailing digit run
+        let base = name.trim_end_matches(|c: char| c.is_ascii_digit());
+        return !base.ends_with('p');
+    }
+    // sd*/vd*/hd* style: a trailing digit means it's a partition
+    false
+}
+
+// total bytes read/written across physical disks since boot
+// sysinfo's DiskExt only exposes capacity, so the per-disk IO counters
+// come from /proc/diskstats (sectors are 512 bytes) the way systemstat does
+fn disk_bytes() -> (u64, u64) {
+    let stats = match std::fs::read_to_string("/proc/diskstats") {
+        Ok(s) => s,
+        Err(_) => return (0, 0),
+    };
+
+    let mut read_sectors: u64 = 0;
+    let mut written_sectors: u64 = 0;
+    for line in stats.lines() {
+        let fields = line.split_whitespace().collect::<Vec<_>>();
+        if fields.len() < 10 {
+            continue;
+        }
+        if !is_physical_disk(fields[2]) {
+            continue;
+        }
+        read_sectors += fields[5].parse::<u64>().unwrap_or(0);
+        written_sectors += fields[9].parse::<u64>().unwrap_or(0);
+    }
+
+    (read_sectors * 512, written_sectors * 512)
+}
+
+fn disk(cfg: &Config) -> String {
+    let (first_read, first_written) = disk_bytes();
+    thread::sleep(cfg.interval);
+    let (second_read, second_written) = disk_bytes();
+    let seconds = cfg.interval.as_secs();
+    let read_bandwidth = second_read.wrapping_sub(first_read) / seconds;
+    let write_bandwidth = second_written.wrapping_sub(first_written) / seconds;
+    let (used_space, total_space) = disk_space();
+    fmt_disk(read_bandwidth, write_bandwidth, used_space, total_space, cfg)
+}
+
+// used/total capacity summed across mounted filesystems
+fn disk_space() -> (u64, u64) {
+    let refresh = RefreshKind::new().with_disks().with_disks_list();
+    let system = System::new_with_specifics(refresh);
+    let disks = system.disks();
+    let total_space: u64 = disks.iter().map(|d| d.total_space()).sum();
+    let available_space: u64 = disks.iter().map(|d| d.available_space()).sum();
+    (total_space.wrapping_sub(available_space), total_space)
+}
+
+fn fmt_disk(
+    read_bandwidth: u64,
+    write_bandwidth: u64,
+    used_space: u64,
+    total_space: u64,
+    cfg: &Config,
+) -> String {
+    let (disk_show, read_name, write_name) = if cfg.with_icons {
+        (" ", " ", " ")
+    } else {
+        ("DISK: ", "R: ", "W: ")
+    };
+    let width = 6;
+    let read_bandwidth = pretty_size(read_bandwidth, cfg.use_binary, cfg.fix_length, width);
+    let write_bandwidth = pretty_size(write_bandwidth, cfg.use_binary, cfg.fix_length, width);
+    if cfg.basic {
+        return format!(
+            "{read_bandwidth}/{write_bandwidth} {}/{}",
+            pretty_size(used_space, cfg.use_binary, cfg.fix_length, width),
+            pretty_size(total_space, cfg.use_binary, cfg.fix_length, width),
+        );
+    }
+    format!(
+        "{read_name}{read_bandwidth:>width$}/s {write_name}{write_bandwidth:>width$}/s \
+         {disk_show}{:>width$}/{}",
+        pretty_size(used_space, cfg.use_binary, cfg.fix_length, width),
+        pretty_size(total_space, cfg.use_binary, cfg.fix_length, width),
+    )
 }
 
 // will try best to fix the value into max_width
@@ -72,15 +182,28 @@ fn max_width_float(v: f64, max_width: usize, remove_trail: bool) -> String {
     }
 }
 
+// base and unit ladder, shared by every byte formatter so SI and IEC
+// conventions stay consistent: thresholds and divisors use the same base
+fn size_units(use_binary: bool) -> (f64, [&'static str; 5]) {
+    if use_binary {
+        (1024.0, ["B", "KiB", "MiB", "GiB", "TiB"])
+    } else {
+        (1000.0, ["B", "KB", "MB", "GB", "TB"])
+    }
+}
+
 // accept bytes show string
-fn pretty_size(s: u64, fix_length: bool, max_width: usize) -> String {
-    let (value, unit) = match s {
-        s if s < 1000 => (s as f64, "B"),
-        s if s < 1000 * 1024 => (s as f64 / 1024.0, "KB"),
-        s if s < 1000 * 1024 * 1024 => (s as f64 / 1024.0 / 1024.0, "MB"),
-        s if s < 1000 * 1024 * 1024 * 1024 => (s as f64 / 1024.0 / 1024.0 / 1024.0, "GB"),
-        _ => (s as f64 / 1024.0 / 1024.0 / 1024.0 / 1024.0, "TB"),
-    };
+fn pretty_size(s: u64, use_binary: bool, fix_length: bool, max_width: usize) -> String {
+    let (base, units) = size_units(use_binary);
+    let mut value = s as f64;
+    let mut unit = units[0];
+    for next in &units[1..] {
+        if value < base {
+            break;
+        }
+        value /= base;
+        unit = next;
+    }
     let value_str = if fix_length {
         let value_max_width = max_width - 2;
         max_width_float(value, value_max_width, true)
@@ -105,14 +228,31 @@ fn mem(cfg: &Config) -> String {
         ("MEM: ", "SWP: ")
     };
     let width = 6;
-    // total mem/swp is fixed no need fill width
-    format!(
-        "{memory_show}{:>width$}/{} {swap_show}{:>width$}/{}",
-        pretty_size(used_mem, cfg.fix_length, width),
-        pretty_size(total_mem, cfg.fix_length, width),
-        pretty_size(used_swap, cfg.fix_length, width),
-        pretty_size(total_swap, cfg.fix_length, width)
-    )
+    // colour severity tracks the used-memory fraction
+    let used_fraction = if total_mem > 0 {
+        used_mem as f64 / total_mem as f64 * 100.0
+    } else {
+        0.0
+    };
+    let text = if cfg.basic {
+        format!(
+            "{}/{} {}/{}",
+            pretty_size(used_mem, cfg.use_binary, cfg.fix_length, width),
+            pretty_size(total_mem, cfg.use_binary, cfg.fix_length, width),
+            pretty_size(used_swap, cfg.use_binary, cfg.fix_length, width),
+            pretty_size(total_swap, cfg.use_binary, cfg.fix_length, width),
+        )
+    } else {
+        // total mem/swp is fixed no need fill width
+        format!(
+            "{memory_show}{:>width$}/{} {swap_show}{:>width$}/{}",
+            pretty_size(used_mem, cfg.use_binary, cfg.fix_length, width),
+            pretty_size(total_mem, cfg.use_binary, cfg.fix_length, width),
+            pretty_size(used_swap, cfg.use_binary, cfg.fix_length, width),
+            pretty_size(total_swap, cfg.use_binary, cfg.fix_length, width)
+        )
+    };
+    colorize(&text, cfg.threshold.severity(used_fraction), cfg)
 }
 
 fn cpu(cfg: &Config) -> String {
@@ -120,23 +260,289 @@ fn cpu(cfg: &Config) -> String {
     let mut system = System::new_with_specifics(refresh);
     thread::sleep(cfg.interval);
     system.refresh_cpu();
-    let processors = system.processors();
-    let processor_num = processors.len();
-    let cpu_usage_avg: f32 =
-        processors.iter().map(|p| p.cpu_usage()).sum::<f32>() / processor_num as f32;
+    let usages: Vec<f32> = system.processors().iter().map(|p| p.cpu_usage()).collect();
+    fmt_cpu(&usages, cfg)
+}
+
+fn fmt_cpu(usages: &[f32], cfg: &Config) -> String {
+    let processor_num = usages.len();
 
     let cpu_show = if cfg.with_icons { " " } else { "CPU: " };
+    let cpu_usage_avg: f32 = usages.iter().sum::<f32>() / processor_num.max(1) as f32;
+
+    let text = if cfg.cpu_per_core {
+        let cores: Vec<String> = usages
+            .iter()
+            .map(|p| max_width_float(*p as f64, 4, false))
+            .collect();
+        if cfg.basic {
+            cores.join("/")
+        } else {
+            format!("{cpu_show}{}", cores.join(" "))
+        }
+    } else {
+        let value = max_width_float(cpu_usage_avg as f64, 4, false);
+        if cfg.basic {
+            value
+        } else {
+            format!("{cpu_show}{value:>4}")
+        }
+    };
+
+    colorize(&text, cfg.threshold.severity(cpu_usage_avg as f64), cfg)
+}
+
+// collect (label, degrees Celsius) for every exposed hwmon sensor on Linux
+fn hwmon_sensors() -> Vec<(String, f64)> {
+    let mut sensors = Vec::new();
+    let hwmons = match std::fs::read_dir("/sys/class/hwmon") {
+        Ok(d) => d,
+        Err(_) => return sensors,
+    };
+    for hwmon in hwmons.flatten() {
+        let dir = hwmon.path();
+        // device name, e.g. "coretemp", "k10temp", "acpitz"
+        let device = std::fs::read_to_string(dir.join("name"))
+            .map(|s| s.trim().to_string())
+            .unwrap_or_default();
+        let entries = match std::fs::read_dir(&dir) {
+            Ok(e) => e,
+            Err(_) => continue,
+        };
+        for entry in entries.flatten() {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            // only tempN_input files carry readings (millidegrees Celsius)
+            if !(name.starts_with("temp") && name.ends_with("_input")) {
+                continue;
+            }
+            let millidegrees = match std::fs::read_to_string(entry.path()) {
+                Ok(s) => match s.trim().parse::<f64>() {
+                    Ok(v) => v,
+                    Err(_) => continue,
+                },
+                Err(_) => continue,
+            };
+            // prefer the matching tempN_label, fall back to the device name
+            let label_path = dir.join(name.replace("_input", "_label"));
+            let label = std::fs::read_to_string(label_path)
+                .map(|s| s.trim().to_string())
+                .unwrap_or_else(|_| device.clone());
+            sensors.push((label, millidegrees / 1000.0));
+        }
+    }
+    sensors
+}
+
+fn temp(cfg: &Config) -> String {
+    let sensors = hwmon_sensors();
+    let temp_show = if cfg.with_icons { " " } else { "TEMP: " };
+
+    let hottest = sensors
+        .iter()
+        .max_by(|a, b| a.1.total_cmp(&b.1));
+    let hottest = match hottest {
+        Some((_, t)) => *t,
+        None => {
+            if cfg.basic {
+                return "N/A".to_string();
+            }
+            return format!("{temp_show}{:>4}", "N/A");
+        }
+    };
+
+    // a package/CPU sensor is the at-a-glance figure people watch
+    let package = sensors
+        .iter()
+        .find(|(label, _)| {
+            let l = label.to_lowercase();
+            l.contains("package") || l.contains("tctl") || l.contains("tdie")
+        })
+        .map(|(_, t)| *t);
+
+    if cfg.basic {
+        return max_width_float(package.unwrap_or(hottest), 4, false);
+    }
+
+    match package {
+        Some(p) => format!(
+            "{temp_show}{:>4}°C (max {:>4}°C)",
+            max_width_float(p, 4, false),
+            max_width_float(hottest, 4, false)
+        ),
+        None => format!("{temp_show}{:>4}°C", max_width_float(hottest, 4, false)),
+    }
+}
+
+fn load(cfg: &Config) -> String {
+    let refresh = if cfg.load_normalized {
+        RefreshKind::new().with_cpu()
+    } else {
+        RefreshKind::new()
+    };
+    let system = System::new_with_specifics(refresh);
+    let avg = system.load_average();
+
+    let load_show = if cfg.with_icons { " " } else { "LOAD: " };
+
+    // normalize the 1-minute figure against logical processors so a value
+    // around 1.0 means "fully saturated" regardless of core count
+    let one = if cfg.load_normalized {
+        let processor_num = system.processors().len().max(1);
+        avg.one / processor_num as f64
+    } else {
+        avg.one
+    };
+
+    if cfg.basic {
+        return format!(
+            "{}/{}/{}",
+            max_width_float(one, 4, false),
+            max_width_float(avg.five, 4, false),
+            max_width_float(avg.fifteen, 4, false)
+        );
+    }
+
     format!(
-        "{cpu_show}{:>4}",
-        max_width_float(cpu_usage_avg as f64, 4, false)
+        "{load_show}{:>4} {:>4} {:>4}",
+        max_width_float(one, 4, false),
+        max_width_float(avg.five, 4, false),
+        max_width_float(avg.fifteen, 4, false)
     )
 }
 
+// severity derived from a metric's value against its thresholds
+#[derive(Clone, Copy)]
+enum Severity {
+    Normal,
+    Warn,
+    Crit,
+}
+
+// warn/crit limits (as a percentage) for at-a-glance colouring
+#[derive(Clone, Copy)]
+struct Threshold {
+    warn: f64,
+    crit: f64,
+}
+
+impl Threshold {
+    fn severity(&self, value: f64) -> Severity {
+        if value >= self.crit {
+            Severity::Crit
+        } else if value >= self.warn {
+            Severity::Warn
+        } else {
+            Severity::Normal
+        }
+    }
+}
+
+// wrap a formatted metric in tmux style directives when colouring is enabled
+fn colorize(text: &str, severity: Severity, cfg: &Config) -> String {
+    if !cfg.color {
+        return text.to_string();
+    }
+    let colour = match severity {
+        Severity::Normal => "default",
+        Severity::Warn => "colour3",
+        Severity::Crit => "colour1",
+    };
+    format!("#[fg={colour}]{text}#[default]")
+}
+
+// the resource classes the widget can report, selected on the command line
+#[derive(Clone, Copy)]
+enum Widget {
+    Net,
+    Disk,
+    Temp,
+    Load,
+    Cpu,
+    Mem,
+}
+
+impl Widget {
+    // one-shot rendering: each widget samples (and sleeps) on its own
+    fn render(self, cfg: &Config) -> String {
+        match self {
+            Widget::Net => network_bandwidth(cfg),
+            Widget::Disk => disk(cfg),
+            Widget::Temp => temp(cfg),
+            Widget::Load => load(cfg),
+            Widget::Cpu => cpu(cfg),
+            Widget::Mem => mem(cfg),
+        }
+    }
+}
+
+// Continuous mode: keep the process alive and print one status line per
+// interval. The cpu `System` is created once and only `refresh_cpu`'d each
+// tick, and network/disk byte counters are kept from tick to tick, so delta
+// metrics (network, disk, cpu) compute bandwidth against the previous tick's
+// snapshot instead of sleeping an extra interval per sample. Network counters,
+// disk space, memory and load are read through a fresh `System` on every
+// tick; only the cpu `System` is reused.
+fn watch(cfg: &Config, widgets: &[Widget]) {
+    let wants_cpu = widgets.iter().any(|w| matches!(w, Widget::Cpu));
+    let mut system = System::new_with_specifics(RefreshKind::new().with_cpu());
+    // prime the cpu counters so the first tick reports a real delta
+    if wants_cpu {
+        system.refresh_cpu();
+    }
+    let mut prev_net = network_bytes();
+    let mut prev_disk = disk_bytes();
+
+    let separator = if cfg.basic { "|" } else { " " };
+    let seconds = cfg.interval.as_secs();
+    loop {
+        thread::sleep(cfg.interval);
+        system.refresh_cpu();
+        let cur_net = network_bytes();
+        let cur_disk = disk_bytes();
+
+        let line: Vec<String> = widgets
+            .iter()
+            .map(|w| match w {
+                Widget::Net => {
+                    let up = cur_net.0.wrapping_sub(prev_net.0) / seconds;
+                    let down = cur_net.1.wrapping_sub(prev_net.1) / seconds;
+                    fmt_network(up, down, cfg)
+                }
+                Widget::Disk => {
+                    let read = cur_disk.0.wrapping_sub(prev_disk.0) / seconds;
+                    let write = cur_disk.1.wrapping_sub(prev_disk.1) / seconds;
+                    let (used, total) = disk_space();
+                    fmt_disk(read, write, used, total, cfg)
+                }
+                Widget::Cpu => {
+                    let usages: Vec<f32> =
+                        system.processors().iter().map(|p| p.cpu_usage()).collect();
+                    fmt_cpu(&usages, cfg)
+                }
+                Widget::Temp => temp(cfg),
+                Widget::Load => load(cfg),
+                Widget::Mem => mem(cfg),
+            })
+            .collect();
+        println!("{}", line.join(separator));
+
+        prev_net = cur_net;
+        prev_disk = cur_disk;
+    }
+}
+
 #[derive(Clone)]
 struct Config {
     with_icons: bool,
     interval: time::Duration,
     fix_length: bool,
+    use_binary: bool,
+    load_normalized: bool,
+    cpu_per_core: bool,
+    basic: bool,
+    watch: bool,
+    color: bool,
+    threshold: Threshold,
 }
 
 impl Default for Config {
@@ -145,6 +551,16 @@ impl Default for Config {
             with_icons: false,
             interval: time::Duration::from_secs(1),
             fix_length: true,
+            use_binary: false,
+            load_normalized: false,
+            cpu_per_core: false,
+            basic: false,
+            watch: false,
+            color: false,
+            threshold: Threshold {
+                warn: 70.0,
+                crit: 90.0,
+            },
         }
     }
 }
@@ -156,16 +572,40 @@ fn main() {
     }
 
     let mut cfg: Config = Default::default();
-    let mut ops = Vec::<fn(&Config) -> String>::new();
+    let mut ops = Vec::<Widget>::new();
 
     let mut args_iter = std::env::args().skip(1);
     while let Some(arg) = args_iter.next() {
         match arg.as_str() {
-            "--net" => ops.push(network_bandwidth),
-            "--cpu" => ops.push(cpu),
-            "--mem" => ops.push(mem),
+            "--net" => ops.push(Widget::Net),
+            "--disk" => ops.push(Widget::Disk),
+            "--temp" => ops.push(Widget::Temp),
+            "--load" => ops.push(Widget::Load),
+            "--load-normalized" => cfg.load_normalized = true,
+            "--cpu-per-core" => cfg.cpu_per_core = true,
+            "--basic" => cfg.basic = true,
+            "--cpu" => ops.push(Widget::Cpu),
+            "--mem" => ops.push(Widget::Mem),
+            "--watch" | "--loop" => cfg.watch = true,
+            "--color" => cfg.color = true,
+            "--warn" => {
+                cfg.threshold.warn = args_iter
+                    .next()
+                    .expect("missing value for warn threshold")
+                    .parse::<f64>()
+                    .expect("bad warn threshold");
+            }
+            "--crit" => {
+                cfg.threshold.crit = args_iter
+                    .next()
+                    .expect("missing value for crit threshold")
+                    .parse::<f64>()
+                    .expect("bad crit threshold");
+            }
             "--with-icons" => cfg.with_icons = true,
             "--no-fix-length" => cfg.fix_length = false,
+            "--binary" => cfg.use_binary = true,
+            "--si" => cfg.use_binary = false,
             "--interval" => {
                 let interval_sec = args_iter
                     .next()
@@ -181,11 +621,17 @@ fn main() {
         }
     }
 
+    // continuous mode shares one System across ticks and never exits
+    if cfg.watch {
+        watch(&cfg, &ops);
+        return;
+    }
+
     let mut threads = vec![];
     let mut outputs = vec![];
     for (i, op) in ops.into_iter().enumerate() {
         let localcfg = cfg.clone();
-        threads.push(thread::spawn(move || (i, op(&localcfg))));
+        threads.push(thread::spawn(move || (i, op.render(&localcfg))));
     }
 
     threads
@@ -193,7 +639,9 @@ fn main() {
         .for_each(|t| outputs.push(t.join().unwrap()));
     outputs.sort_by_key(|(i, _)| *i);
     let outputs: Vec<String> = outputs.into_iter().map(|(_, s)| s).collect();
-    println!("{}", outputs.join(" "));
+    // basic mode packs segments tight with a single separator
+    let separator = if cfg.basic { "|" } else { " " };
+    println!("{}", outputs.join(separator));
 }
 
 #[cfg(test)]
@@ -203,19 +651,68 @@ mod test {
 
     #[test]
     fn test_pretty_size() {
-        let test_size_fixed_length_width_6 =
-            |s: u64, expected: &str| assert_eq!(pretty_size(s, true, 7), expected);
-
-        test_size_fixed_length_width_6(999, "999B");
-        test_size_fixed_length_width_6(1000, "0.977KB");
-        test_size_fixed_length_width_6(1024, "1KB");
-        test_size_fixed_length_width_6(2 * 1024, "2KB");
-        test_size_fixed_length_width_6(999 * 1024 - 10, "999KB");
-        test_size_fixed_length_width_6(1 * 1000 * 1024, "0.977MB");
-        test_size_fixed_length_width_6(1 * 1024 * 1024, "1MB");
-        test_size_fixed_length_width_6(1 * 1000 * 1024 * 1024, "0.977GB");
-        test_size_fixed_length_width_6(1 * 1024 * 1024 * 1024, "1GB");
-        test_size_fixed_length_width_6(1 * 1000 * 1024 * 1024 * 1024, "0.977TB");
-        test_size_fixed_length_width_6(1 * 1024 * 1024 * 1024 * 1024, "1TB");
+        // decimal (SI): 1000-based thresholds and divisors
+        let si = |s: u64, expected: &str| assert_eq!(pretty_size(s, false, true, 7), expected);
+        // binary (IEC): 1024-based thresholds and divisors
+        let bin = |s: u64, expected: &str| assert_eq!(pretty_size(s, true, true, 7), expected);
+
+        si(999, "999B");
+        si(1000, "1KB");
+        si(1500, "1.5KB");
+        si(1000 * 1000, "1MB");
+        si(1000 * 1000 * 1000, "1GB");
+        si(1000 * 1000 * 1000 * 1000, "1TB");
+
+        bin(1023, "1023B");
+        bin(1024, "1KiB");
+        // 1024-based thresholds: 1000 bytes stays below 1 KiB
+        bin(1000, "1000B");
+        bin(2 * 1024, "2KiB");
+        bin(1024 * 1024, "1MiB");
+        bin(1000 * 1024, "1000KiB");
+        bin(1024 * 1024 * 1024, "1GiB");
+        bin(1024u64 * 1024 * 1024 * 1024, "1TiB");
+    }
+
+    #[test]
+    fn test_is_physical_disk() {
+        // whole disks, including NVMe/eMMC names that end in a digit
+        assert!(is_physical_disk("sda"));
+        assert!(is_physical_disk("vda"));
+        assert!(is_physical_disk("nvme0n1"));
+        assert!(is_physical_disk("mmcblk0"));
+
+        // partitions are excluded
+        assert!(!is_physical_disk("sda1"));
+        assert!(!is_physical_disk("vda1"));
+        assert!(!is_physical_disk("nvme0n1p1"));
+        assert!(!is_physical_disk("mmcblk0p1"));
+
+        // virtual devices are excluded
+        assert!(!is_physical_disk("loop0"));
+    }
+
+    #[test]
+    fn test_severity() {
+        let t = Threshold {
+            warn: 70.0,
+            crit: 90.0,
+        };
+        // below warn
+        assert!(matches!(t.severity(0.0), Severity::Normal));
+        assert!(matches!(t.severity(69.9), Severity::Normal));
+        // at/above warn, below crit
+        assert!(matches!(t.severity(70.0), Severity::Warn));
+        assert!(matches!(t.severity(89.9), Severity::Warn));
+        // at/above crit
+        assert!(matches!(t.severity(90.0), Severity::Crit));
+        assert!(matches!(t.severity(100.0), Severity::Crit));
+
+        // when warn exceeds crit, crit wins first and Warn is unreachable
+        let inverted = Threshold {
+            warn: 90.0,
+            crit: 70.0,
+        };
+        assert!(matches!(inverted.severity(80.0), Severity::Crit));
     }
 }